@@ -0,0 +1,40 @@
+impl<T> [T] {
+  pub fn as_array<const N: usize>(&self) -> Option<&[T; N]> {
+    if self.len() == N {
+      // SAFETY: lengths match, and `[T; N]` has the same layout as a
+      // slice of length `N`.
+      Some(unsafe { self.as_array_unchecked() })
+    } else {
+      None
+    }
+  }
+
+  pub fn as_mut_array<const N: usize>(&mut self) -> Option<&mut [T; N]> {
+    if self.len() == N {
+      // SAFETY: lengths match, and `[T; N]` has the same layout as a
+      // slice of length `N`.
+      Some(unsafe { self.as_mut_array_unchecked() })
+    } else {
+      None
+    }
+  }
+
+  // SAFETY: caller guarantees `self.len() == N`.
+  pub unsafe fn as_array_unchecked<const N: usize>(&self) -> &[T; N] {
+    unsafe { &*(self.as_ptr() as *const [T; N]) }
+  }
+
+  // SAFETY: caller guarantees `self.len() == N`.
+  pub unsafe fn as_mut_array_unchecked<const N: usize>(&mut self) -> &mut [T; N] {
+    unsafe { &mut *(self.as_mut_ptr() as *mut [T; N]) }
+  }
+}
+
+// When the slice's length is known to the optimizer, the `self.len() == N`
+// comparison in `as_array` is decided at compile time; where it holds, the
+// checked form collapses to the same pointer reinterpretation as the
+// unchecked form -- there is no length test left on the hot path.
+fn subscript_array(slice: &mut [i32], i: usize, j: usize) {
+  let array: &mut [i32; 10] = unsafe { slice.as_mut_array_unchecked() };
+  unsafe { *array.get_unchecked_mut(i) += *array.get_unchecked(j); }
+}
@@ -0,0 +1,58 @@
+use core::ptr::NonNull;
+
+// Complements the existing `len()` query on these types with the raw-
+// pointer analogue of the reference-based unchecked subscripts: no
+// intermediate reference is ever formed, so a partially initialized or
+// aliased slice can be indexed without tripping the borrow checker or
+// asserting validity prematurely.
+impl<T> *const [T] {
+  // SAFETY: caller guarantees `index < self.len()` and that `self` is
+  // valid for reads of an element at that index.
+  pub unsafe fn get_unchecked(self, index: usize) -> *const T {
+    unsafe { (self as *const T).add(index) }
+  }
+}
+
+impl<T> *mut [T] {
+  // SAFETY: caller guarantees `index < self.len()` and that `self` is
+  // valid for reads of an element at that index.
+  pub unsafe fn get_unchecked(self, index: usize) -> *const T {
+    unsafe { (self as *const T).add(index) }
+  }
+
+  // SAFETY: caller guarantees `index < self.len()` and that `self` is
+  // valid for writes to an element at that index.
+  pub unsafe fn get_unchecked_mut(self, index: usize) -> *mut T {
+    unsafe { (self as *mut T).add(index) }
+  }
+}
+
+fn subscript_raw(slice: *mut [i32], i: usize, j: usize) {
+  unsafe {
+    let pi = slice.get_unchecked_mut(i);
+    let pj = slice.get_unchecked(j);
+    *pi += *pj;
+  }
+}
+
+impl<T> NonNull<[T]> {
+  // SAFETY: caller guarantees `index < self.len()` and that `self` is
+  // valid for reads of an element at that index.
+  pub unsafe fn get_unchecked(self, index: usize) -> NonNull<T> {
+    unsafe { NonNull::new_unchecked(self.as_ptr().get_unchecked(index) as *mut T) }
+  }
+
+  // SAFETY: caller guarantees `index < self.len()` and that `self` is
+  // valid for writes to an element at that index.
+  pub unsafe fn get_unchecked_mut(self, index: usize) -> NonNull<T> {
+    unsafe { NonNull::new_unchecked(self.as_ptr().get_unchecked_mut(index)) }
+  }
+}
+
+fn subscript_nonnull(slice: NonNull<[i32]>, i: usize, j: usize) {
+  unsafe {
+    let pi = slice.get_unchecked_mut(i);
+    let pj = slice.get_unchecked(j);
+    *pi.as_ptr() += *pj.as_ptr();
+  }
+}
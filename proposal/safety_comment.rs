@@ -0,0 +1,47 @@
+// The comment must be adjacent to the `unsafe` block itself, not to the
+// enclosing `fn` -- so it sits on the line directly above `unsafe {`,
+// below the signature, rather than above the whole item.
+fn subscript_array(mut array: [i32; 10], i: usize, j: usize) {
+  // SAFETY: caller guarantees `i` and `j` are both in bounds for `array`.
+  unsafe { *array.get_unchecked_mut(i) += *array.get_unchecked(j); }
+}
+
+fn subscript_vector(mut vec: Vec<i32>, i: usize, j: usize) {
+  // SAFETY: caller guarantees `i` and `j` are both in bounds for `vec`.
+  unsafe { *vec.get_unchecked_mut(i) += *vec.get_unchecked(j); }
+}
+
+// error: `unsafe` block requires a `// SAFETY:` comment on the line
+// immediately above it
+fn subcript_slice(slice: &mut [i32], i: usize, j: usize) {
+  unsafe { *slice.get_unchecked_mut(i) += *slice.get_unchecked(j); }
+}
+
+// error: `// SAFETY:` comment must be adjacent to the block; a blank
+// line (or unrelated code) in between does not count
+
+fn subscript_array_checked(mut array: [i32; 10], i: usize, j: usize) {
+  // SAFETY: caller guarantees `i` and `j` are both in bounds for `array`.
+
+  unsafe { *array.get_unchecked_mut(i) += *array.get_unchecked(j); }
+}
+
+// Suppressed per-site: with the lint allowed on this function, its bare
+// `unsafe` block below is not reported.
+#[allow(missing_safety_comment)]
+fn subscript_array_allowed(mut array: [i32; 10], i: usize) -> i32 {
+  unsafe { *array.get_unchecked(i) }
+}
+
+// SAFETY: caller guarantees `i` is in bounds for `array`.
+unsafe fn get_unchecked_mut(array: &mut [i32; 10], i: usize) -> &mut i32 {
+  // SAFETY: `i` is in bounds by this function's own contract.
+  unsafe { &mut *array.as_mut_ptr().add(i) }
+}
+
+// error: `unsafe fn` declaration requires a `// SAFETY:` comment on the
+// line immediately above it, documenting the invariant callers must
+// uphold
+unsafe fn get_unchecked(array: &[i32; 10], j: usize) -> &i32 {
+  unsafe { &*array.as_ptr().add(j) }
+}
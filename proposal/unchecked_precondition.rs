@@ -0,0 +1,76 @@
+macro_rules! precondition {
+  ($cond:expr, $($arg:tt)+) => {
+    if cfg!(debug_assertions) && !$cond {
+      panic!($($arg)+);
+    }
+  };
+}
+
+impl<T, const N: usize> [T; N] {
+  pub unsafe fn get_unchecked(&self, i: usize) -> &T {
+    unsafe {
+      precondition!(i < N, "index out of bounds: the len is {} but the index is {}", N, i);
+      &*self.as_ptr().add(i)
+    }
+  }
+
+  pub unsafe fn get_unchecked_mut(&mut self, i: usize) -> &mut T {
+    unsafe {
+      precondition!(i < N, "index out of bounds: the len is {} but the index is {}", N, i);
+      &mut *self.as_mut_ptr().add(i)
+    }
+  }
+}
+
+impl<T> [T] {
+  pub unsafe fn get_unchecked(&self, i: usize) -> &T {
+    unsafe {
+      let len = self.len();
+      precondition!(i < len, "index out of bounds: the len is {} but the index is {}", len, i);
+      &*self.as_ptr().add(i)
+    }
+  }
+
+  pub unsafe fn get_unchecked_mut(&mut self, i: usize) -> &mut T {
+    unsafe {
+      let len = self.len();
+      precondition!(i < len, "index out of bounds: the len is {} but the index is {}", len, i);
+      &mut *self.as_mut_ptr().add(i)
+    }
+  }
+}
+
+impl<T> Vec<T> {
+  pub unsafe fn get_unchecked(&self, j: usize) -> &T {
+    unsafe {
+      let len = self.len();
+      precondition!(j < len, "index out of bounds: the len is {} but the index is {}", len, j);
+      &*self.as_ptr().add(j)
+    }
+  }
+
+  pub unsafe fn get_unchecked_mut(&mut self, j: usize) -> &mut T {
+    unsafe {
+      let len = self.len();
+      precondition!(j < len, "index out of bounds: the len is {} but the index is {}", len, j);
+      &mut *self.as_mut_ptr().add(j)
+    }
+  }
+}
+
+// In a debug build, a call like `array.get_unchecked_mut(i)` with an
+// out-of-range `i` now traps at the call site instead of reading out of
+// bounds. In a release build `cfg!(debug_assertions)` is false and the
+// check is optimized away entirely, leaving the same pointer arithmetic
+// as before.
+fn subscript_array(mut array: [i32; 10], i: usize, j: usize) {
+  unsafe { *array.get_unchecked_mut(i) += *array.get_unchecked(j); }
+}
+
+fn subcript_slice(slice: &mut [i32], i: usize, j: usize) {
+  unsafe { *slice.get_unchecked_mut(i) += *slice.get_unchecked(j); }
+}
+
+fn subscript_vector(mut vec: Vec<i32>, i: usize, j: usize) {
+  unsafe { *vec.get_unchecked_mut(i) += *vec.get_unchecked(j); }
+}
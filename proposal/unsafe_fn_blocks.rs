@@ -0,0 +1,22 @@
+// Before: the body of an `unsafe fn` is itself an unsafe context, so an
+// unsafe operation can appear bare, with nothing marking where the
+// dangerous line is.
+//
+// Under the new mode this no longer compiles:
+// error: call to unsafe function `add` is unsafe and requires unsafe
+// block, even inside an `unsafe fn`
+unsafe fn get_unchecked_mut(array: &mut [i32; 10], i: usize) -> &mut i32 {
+  &mut *array.as_mut_ptr().add(i)
+}
+
+// After: declaring a function `unsafe` only restricts who may call it.
+// It no longer implicitly covers the body, so each unsafe operation must
+// sit in its own `unsafe` block, same as in a safe function.
+unsafe fn get_unchecked_mut_explicit(array: &mut [i32; 10], i: usize) -> &mut i32 {
+  unsafe { &mut *array.as_mut_ptr().add(i) }
+}
+
+fn subscript_array(mut array: [i32; 10], i: usize, j: usize) {
+  unsafe { *get_unchecked_mut_explicit(&mut array, i) += 1; }
+  unsafe { *get_unchecked_mut_explicit(&mut array, j) += 1; }
+}
@@ -0,0 +1,29 @@
+fn subscript_array(mut array: [i32; 10], i: usize, j: usize) {
+  // OK: `get_unchecked_mut` and `get_unchecked` are unsafe operations, so
+  // the enclosing `unsafe` block is load-bearing.
+  unsafe { *array.get_unchecked_mut(i) += *array.get_unchecked(j); }
+}
+
+fn subscript_checked(array: [i32; 10], i: usize) -> i32 {
+  // error: unnecessary `unsafe` on block
+  //
+  // note: block contains no unsafe operations to act as the enclosing
+  // scope for
+  unsafe { array[i] }
+}
+
+fn nested(slice: &mut [i32], i: usize, j: usize) -> i32 {
+  // OK: the outer block directly contains the unsafe `get_unchecked`
+  // call, so it is load-bearing even though the block also contains the
+  // redundant inner block below.
+  unsafe {
+    let a = *slice.get_unchecked(j);
+
+    // error: unnecessary `unsafe` on block
+    //
+    // note: `slice[i]` is not an unsafe operation, and the outer block
+    // is already unsafe, so this inner block is redundant on both counts
+    let b = unsafe { slice[i] };
+    a + b
+  }
+}